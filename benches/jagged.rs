@@ -0,0 +1,87 @@
+#[macro_use]
+extern crate criterion;
+extern crate jagged_array;
+
+use criterion::{black_box, BenchmarkId, Criterion};
+
+use jagged_array::JaggedArray;
+
+// A handful of (row count, row width) shapes so we can see how the flat storage
+// behaves as arrays get taller and as individual rows get wider.
+const SHAPES: &[(usize, usize)] = &[(16, 4), (256, 8), (1024, 16)];
+
+fn sample_rows(rows: usize, width: usize) -> Vec<Vec<u32>> {
+    (0..rows)
+        .map(|row| (0..width).map(|col| (row * width + col) as u32).collect())
+        .collect()
+}
+
+fn bench_push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push");
+    for &(rows, width) in SHAPES {
+        let input = sample_rows(rows, width);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{}x{}", rows, width)),
+            &input,
+            |b, input| {
+                b.iter(|| {
+                    let mut jagged: JaggedArray<u32> = JaggedArray::new();
+                    for row in input {
+                        jagged.push(black_box(row));
+                    }
+                    jagged
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iter");
+    for &(rows, width) in SHAPES {
+        let jagged: JaggedArray<u32> = sample_rows(rows, width).iter().collect();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{}x{}", rows, width)),
+            &jagged,
+            |b, jagged| {
+                b.iter(|| {
+                    let mut total = 0u64;
+                    for row in jagged.iter() {
+                        for &element in row {
+                            total += u64::from(element);
+                        }
+                    }
+                    black_box(total)
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get");
+    for &(rows, width) in SHAPES {
+        let jagged: JaggedArray<u32> = sample_rows(rows, width).iter().collect();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{}x{}", rows, width)),
+            &jagged,
+            |b, jagged| {
+                b.iter(|| {
+                    let mut total = 0u64;
+                    for n in 0..jagged.len() {
+                        for &element in jagged.get(black_box(n)).unwrap() {
+                            total += u64::from(element);
+                        }
+                    }
+                    black_box(total)
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_push, bench_iter, bench_get);
+criterion_main!(benches);
@@ -1,9 +1,39 @@
 extern crate smallvec;
 
+#[cfg(feature = "arbitrary")]
+extern crate arbitrary;
+
 use std::mem;
+use std::fmt;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 use std::iter::{FromIterator, Extend};
 use smallvec::{SmallVec, Array};
 
+/// A type that can be used as an outer row index into a `JaggedArray`.
+///
+/// This mirrors the `Idx` trait used by the `IndexVec` family in `rustc_index`:
+/// the flat storage is still keyed by `usize`, but the public surface speaks in
+/// terms of a caller-chosen newtype so that row handles from different jagged
+/// arrays cannot be mixed up by accident.
+pub trait Idx: Copy {
+    /// Construct an index from its flat `usize` position.
+    fn new(idx: usize) -> Self;
+    /// The flat `usize` position of this index.
+    fn index(self) -> usize;
+}
+
+impl Idx for usize {
+    fn new(idx: usize) -> Self {
+        idx
+    }
+
+    fn index(self) -> usize {
+        self
+    }
+}
+
 // TODO:
 // We store redundant `indices` here for better complexity. It might be good to extract this to a
 // seperate struct to allow us to be generic over whether we store only `lengths` or both fields.
@@ -13,83 +43,172 @@ use smallvec::{SmallVec, Array};
 // `LengthAndIndices(Vec<usize>, Vec<usize>)` struct, then implementing `GetNthLength` and
 // `GetNthIndex` traits. In the `LengthOnly` case we can calculate it each time. If we do this it
 // would also be good to use `VecLike` for all of the fields (`elements` included).
-// TODO: Skip one element in indices, since the first element is always 0.
-pub struct JaggedArray<Element, A: Array<Item = usize> = [usize; 8]> {
+//
+// `indices` stores the cumulative *end* offset of each row (one entry per row);
+// the first row always starts at 0 and a row's start is the previous row's end.
+pub struct JaggedArray<Element, A: Array<Item = usize> = [usize; 8], I: Idx = usize> {
     elements: Vec<Element>,
     indices: SmallVec<A>,
+    _marker: PhantomData<I>,
 }
 
 pub struct Iter<'a, Element: 'a> {
     elements: &'a [Element],
+    // The *ends* of the rows not yet yielded; `start` is the offset of the next
+    // row's first element within `elements`.
     indices: &'a [usize],
+    start: usize,
 }
 
 impl<'a, Element> Iterator for Iter<'a, Element> {
     type Item = &'a [Element];
 
-    // TODO: We can trust all of this - do it unsafely
     fn next(&mut self) -> Option<Self::Item> {
-        if self.elements.is_empty() {
-            return None;
-        }
+        // `split_first` gives us the single termination branch; every split
+        // below is provably in bounds (the invariant on `indices`), so we skip
+        // the per-step bounds checks and assert them only in debug builds.
+        let (&end, rest) = self.indices.split_first()?;
+        let start = self.start;
 
-        let (now_is, rest_is) = self.indices.split_at(1);
+        debug_assert!(start <= end);
+        debug_assert!(end <= self.elements.len());
 
-        if rest_is.is_empty() {
-            return Some(mem::replace(&mut self.elements, &[]));
-        }
+        let row = unsafe { self.elements.get_unchecked(start..end) };
 
-        let now_i = now_is[0];
-        let next_i = rest_is[0];
-        let now_len = next_i - now_i;
-        let (now_el, rest_el) = self.elements.split_at(now_len);
+        self.start = end;
+        self.indices = rest;
 
-        self.indices = rest_is;
-        self.elements = rest_el;
+        Some(row)
+    }
 
-        Some(now_el)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.indices.len();
+        (len, Some(len))
     }
 }
 
+impl<'a, Element> ExactSizeIterator for Iter<'a, Element> {}
+
 pub struct IterMut<'a, Element: 'a> {
     elements: &'a mut [Element],
     indices: &'a [usize],
+    start: usize,
 }
 
 impl<'a, Element> Iterator for IterMut<'a, Element> {
     type Item = &'a mut [Element];
 
-    // TODO: We can trust all of this - do it unsafely
     fn next(&mut self) -> Option<Self::Item> {
-        if self.elements.is_empty() {
-            return None;
-        }
+        let (&end, rest) = self.indices.split_first()?;
+        let now_len = end - self.start;
 
-        let (now_is, rest_is) = self.indices.split_at(1);
+        let slice = mem::take(&mut self.elements);
 
-        if rest_is.is_empty() {
-            return Some(mem::replace(&mut self.elements, &mut []));
-        }
+        debug_assert!(now_len <= slice.len());
 
-        let now_i = now_is[0];
-        let next_i = rest_is[0];
-        let now_len = next_i - now_i;
-        let (now_el, rest_el) = mem::replace(&mut self.elements, &mut []).split_at_mut(now_len);
+        let (now_el, rest_el) = slice.split_at_mut(now_len);
 
-        self.indices = rest_is;
+        self.start = end;
+        self.indices = rest;
         self.elements = rest_el;
 
         Some(now_el)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.indices.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, Element> ExactSizeIterator for IterMut<'a, Element> {}
+
+/// An owning iterator over the rows of a `JaggedArray`, yielding each row as a
+/// `Vec<Element>`.
+pub struct IntoIter<Element> {
+    rows: ::std::vec::IntoIter<Vec<Element>>,
+}
+
+/// A draining iterator that empties a `JaggedArray`, yielding each row as a
+/// `Vec<Element>` and leaving the array reusable afterwards.
+pub struct Drain<'a, Element: 'a> {
+    rows: ::std::vec::IntoIter<Vec<Element>>,
+    _marker: PhantomData<&'a mut ()>,
+}
+
+// Split the flat buffer into one owned `Vec` per row. We split from the back so
+// each `split_off` only has to move a single row's worth of elements.
+fn into_rows<Element>(mut elements: Vec<Element>, indices: &[usize]) -> Vec<Vec<Element>> {
+    let mut rows = Vec::with_capacity(indices.len());
+
+    // `indices` holds row ends, so a row's start is the previous row's end.
+    for n in (0..indices.len()).rev() {
+        let start = if n == 0 { 0 } else { indices[n - 1] };
+        rows.push(elements.split_off(start));
+    }
+
+    rows.reverse();
+    rows
+}
+
+impl<Element> Iterator for IntoIter<Element> {
+    type Item = Vec<Element>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<Element> DoubleEndedIterator for IntoIter<Element> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.rows.next_back()
+    }
+}
+
+impl<Element> ExactSizeIterator for IntoIter<Element> {}
+
+impl<'a, Element> Iterator for Drain<'a, Element> {
+    type Item = Vec<Element>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<'a, Element> DoubleEndedIterator for Drain<'a, Element> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.rows.next_back()
+    }
 }
 
-impl<Element, A: Array<Item = usize>> Default for JaggedArray<Element, A> {
+impl<'a, Element> ExactSizeIterator for Drain<'a, Element> {}
+
+impl<Element, A: Array<Item = usize>, I: Idx> IntoIterator for JaggedArray<Element, A, I> {
+    type Item = Vec<Element>;
+    type IntoIter = IntoIter<Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            rows: into_rows(self.elements, &self.indices).into_iter(),
+        }
+    }
+}
+
+impl<Element, A: Array<Item = usize>, I: Idx> Default for JaggedArray<Element, A, I> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<'a, Element, A: Array<Item = usize>> IntoIterator for &'a JaggedArray<Element, A> {
+impl<'a, Element, A: Array<Item = usize>, I: Idx> IntoIterator for &'a JaggedArray<Element, A, I> {
     type Item = <Self::IntoIter as Iterator>::Item;
     type IntoIter = Iter<'a, Element>;
 
@@ -98,7 +217,8 @@ impl<'a, Element, A: Array<Item = usize>> IntoIterator for &'a JaggedArray<Eleme
     }
 }
 
-impl<'a, Element, A: Array<Item = usize>> IntoIterator for &'a mut JaggedArray<Element, A> {
+impl<'a, Element, A: Array<Item = usize>, I: Idx> IntoIterator
+    for &'a mut JaggedArray<Element, A, I> {
     type Item = <Self::IntoIter as Iterator>::Item;
     type IntoIter = IterMut<'a, Element>;
 
@@ -107,32 +227,71 @@ impl<'a, Element, A: Array<Item = usize>> IntoIterator for &'a mut JaggedArray<E
     }
 }
 
-impl<Element, A: Array<Item = usize>> JaggedArray<Element, A> {
+impl<Element, A: Array<Item = usize>, I: Idx> JaggedArray<Element, A, I> {
     pub fn new() -> Self {
         JaggedArray {
             elements: Default::default(),
             indices: Default::default(),
+            _marker: PhantomData,
         }
     }
 
     pub fn singleton(vec: Vec<Element>) -> Self {
+        let end = vec.len();
         JaggedArray {
             elements: vec,
-            indices: SmallVec::from_slice(&[0]),
+            indices: SmallVec::from_slice(&[end]),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create an empty array with the backing buffers pre-sized for `rows` rows
+    /// holding `total_elements` elements in total.
+    pub fn with_capacity(rows: usize, total_elements: usize) -> Self {
+        JaggedArray {
+            elements: Vec::with_capacity(total_elements),
+            indices: SmallVec::with_capacity(rows),
+            _marker: PhantomData,
         }
     }
 
-    pub fn iter(&self) -> Iter<Element> {
+    /// Reserve capacity for at least `rows` more rows and `elements` more
+    /// elements across the two backing buffers.
+    pub fn reserve(&mut self, rows: usize, elements: usize) {
+        self.indices.reserve(rows);
+        self.elements.reserve(elements);
+    }
+
+    /// Like `reserve`, but requesting the minimum capacity from each buffer.
+    pub fn reserve_exact(&mut self, rows: usize, elements: usize) {
+        self.indices.reserve_exact(rows);
+        self.elements.reserve_exact(elements);
+    }
+
+    /// Shrink both backing buffers to fit their current contents.
+    pub fn shrink_to_fit(&mut self) {
+        self.indices.shrink_to_fit();
+        self.elements.shrink_to_fit();
+    }
+
+    /// The current `(rows, elements)` capacities of the two backing buffers.
+    pub fn capacity(&self) -> (usize, usize) {
+        (self.indices.capacity(), self.elements.capacity())
+    }
+
+    pub fn iter(&self) -> Iter<'_, Element> {
         Iter {
             elements: &self.elements,
             indices: &self.indices,
+            start: 0,
         }
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<Element> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, Element> {
         IterMut {
             elements: &mut self.elements,
-            indices: &mut self.indices,
+            indices: &self.indices,
+            start: 0,
         }
     }
 
@@ -140,60 +299,176 @@ impl<Element, A: Array<Item = usize>> JaggedArray<Element, A> {
         self.indices.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// The row index that would be assigned to the next pushed row.
+    pub fn next_index(&self) -> I {
+        I::new(self.len())
+    }
+
+    // `indices` stores the cumulative end offset of each row; the first row
+    // starts at 0, so a row's start is the previous row's end.
     fn get_index_len(&self, n: usize) -> Option<(usize, usize)> {
-        self.indices
-            .get(n)
-            .map(|start| {
-                     let end = self.indices
-                         .get(n + 1)
-                         .map(|i| *i)
-                         .unwrap_or(self.elements.len());
-                     (*start, end - start)
-                 })
-    }
-
-    pub fn get(&self, n: usize) -> Option<&[Element]> {
-        self.get_index_len(n)
+        self.indices.get(n).map(|&end| {
+            let start = if n == 0 { 0 } else { self.indices[n - 1] };
+            (start, end - start)
+        })
+    }
+
+    pub fn get(&self, n: I) -> Option<&[Element]> {
+        self.get_index_len(n.index())
             .map(|(index, len)| &self.elements[index..index + len])
     }
 
-    pub fn get_mut(&mut self, n: usize) -> Option<&mut [Element]> {
+    pub fn get_mut(&mut self, n: I) -> Option<&mut [Element]> {
         // Explicit if let instead of `.map` to prevent borrowck errors
-        if let Some((index, len)) = self.get_index_len(n) {
+        if let Some((index, len)) = self.get_index_len(n.index()) {
             Some(&mut self.elements[index..index + len])
         } else {
             None
         }
     }
+
+    /// Return row `n` without bounds-checking.
+    ///
+    /// # Safety
+    ///
+    /// `n.index()` must be a valid row index (`< self.len()`).
+    pub unsafe fn get_unchecked(&self, n: I) -> &[Element] {
+        let n = n.index();
+        let start = if n == 0 { 0 } else { *self.indices.get_unchecked(n - 1) };
+        let end = *self.indices.get_unchecked(n);
+
+        debug_assert!(start <= end);
+        debug_assert!(end <= self.elements.len());
+
+        self.elements.get_unchecked(start..end)
+    }
+
+    /// Return row `n` mutably without bounds-checking.
+    ///
+    /// # Safety
+    ///
+    /// `n.index()` must be a valid row index (`< self.len()`).
+    pub unsafe fn get_unchecked_mut(&mut self, n: I) -> &mut [Element] {
+        let n = n.index();
+        let start = if n == 0 { 0 } else { *self.indices.get_unchecked(n - 1) };
+        let end = *self.indices.get_unchecked(n);
+
+        debug_assert!(start <= end);
+        debug_assert!(end <= self.elements.len());
+
+        self.elements.get_unchecked_mut(start..end)
+    }
+
+    /// Remove the last row and return its elements, or `None` if empty.
+    pub fn pop(&mut self) -> Option<Vec<Element>> {
+        self.indices.pop()?;
+        let start = self.indices.last().copied().unwrap_or(0);
+        Some(self.elements.split_off(start))
+    }
+
+    /// Remove the row at position `n`, returning its elements.
+    pub fn remove(&mut self, n: I) -> Vec<Element> {
+        let n = n.index();
+        let (start, len) = self.get_index_len(n)
+            .expect("row index out of bounds");
+        let row = self.elements.drain(start..start + len).collect();
+        self.indices.remove(n);
+        // Every row after `n` now starts `len` elements earlier.
+        for index in &mut self.indices[n..] {
+            *index -= len;
+        }
+        row
+    }
+
+    /// Shorten the array to `len` rows, dropping the rest.
+    pub fn truncate(&mut self, len: I) {
+        let len = len.index();
+        if len >= self.indices.len() {
+            return;
+        }
+
+        let start = if len == 0 { 0 } else { self.indices[len - 1] };
+        self.elements.truncate(start);
+        self.indices.truncate(len);
+    }
+
+    /// Remove every row, leaving the array empty.
+    pub fn clear(&mut self) {
+        self.elements.clear();
+        self.indices.clear();
+    }
+
+    /// Remove every row, yielding each as an owned `Vec<Element>`. The array is
+    /// left empty and reusable once the iterator is dropped.
+    pub fn drain(&mut self) -> Drain<'_, Element> {
+        let elements = mem::take(&mut self.elements);
+        let indices = mem::take(&mut self.indices);
+
+        Drain {
+            rows: into_rows(elements, &indices).into_iter(),
+            _marker: PhantomData,
+        }
+    }
 }
 
-impl<Element: Clone, A: Array<Item = usize>> JaggedArray<Element, A> {
-    pub fn push(&mut self, slice: &[Element]) {
-        let new_index = self.elements.len();
-        self.indices.push(new_index);
+impl<Element: Clone, A: Array<Item = usize>, I: Idx> JaggedArray<Element, A, I> {
+    /// Push a new row, returning the index assigned to it.
+    pub fn push(&mut self, slice: &[Element]) -> I {
+        let row = self.next_index();
         self.elements.extend_from_slice(slice);
+        self.indices.push(self.elements.len());
+        row
+    }
+
+    /// Insert a new row at position `n`, shifting later rows to the right.
+    pub fn insert(&mut self, n: I, slice: &[Element]) {
+        let n = n.index();
+        let len = slice.len();
+        let start = if n == 0 { 0 } else { self.indices[n - 1] };
+
+        self.elements
+            .splice(start..start, slice.iter().cloned());
+        // Every existing row from `n` onwards shifts `len` elements to the right.
+        for index in &mut self.indices[n..] {
+            *index += len;
+        }
+        self.indices.insert(n, start + len);
+    }
+
+    /// Swap the two rows at positions `a` and `b`.
+    pub fn swap(&mut self, a: I, b: I) {
+        let a = a.index();
+        let b = b.index();
+        if a == b {
+            return;
+        }
+
+        let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+        let hi_row = self.remove(I::new(hi));
+        let lo_row = self.remove(I::new(lo));
+        self.insert(I::new(lo), &hi_row);
+        self.insert(I::new(hi), &lo_row);
     }
 }
 
-impl<Element: Clone, A: Array<Item = usize>, Slice: AsRef<[Element]>> Extend<Slice>
-    for JaggedArray<Element, A> {
+impl<Element: Clone, A: Array<Item = usize>, I: Idx, Slice: AsRef<[Element]>> Extend<Slice>
+    for JaggedArray<Element, A, I> {
     fn extend<It: IntoIterator<Item = Slice>>(&mut self, iterator: It) {
-        let mut total_length: usize = self.elements.len();
-
         for slice in iterator {
             let slice: &[Element] = slice.as_ref();
-            let len = slice.len();
 
-            self.indices.push(total_length);
             self.elements.extend_from_slice(slice);
-
-            total_length += len;
+            self.indices.push(self.elements.len());
         }
     }
 }
 
-impl<Element: Clone, A: Array<Item = usize>, Slice: AsRef<[Element]>> FromIterator<Slice>
-    for JaggedArray<Element, A> {
+impl<Element: Clone, A: Array<Item = usize>, I: Idx, Slice: AsRef<[Element]>> FromIterator<Slice>
+    for JaggedArray<Element, A, I> {
     fn from_iter<It: IntoIterator<Item = Slice>>(iterator: It) -> Self {
         let mut out: Self = Default::default();
         out.extend(iterator);
@@ -201,6 +476,134 @@ impl<Element: Clone, A: Array<Item = usize>, Slice: AsRef<[Element]>> FromIterat
     }
 }
 
+impl<Element, A: Array<Item = usize>, I: Idx> JaggedArray<Element, A, I> {
+    // Shared by the cross-type `PartialEq` impls below: compare our rows, in
+    // order, against an iterator of slices.
+    fn eq_rows<'b, It>(&self, rows: It) -> bool
+    where
+        Element: PartialEq + 'b,
+        It: IntoIterator<Item = &'b [Element]>,
+    {
+        let mut ours = self.iter();
+        let mut theirs = rows.into_iter();
+
+        loop {
+            match (ours.next(), theirs.next()) {
+                (Some(a), Some(b)) => {
+                    if a != b {
+                        return false;
+                    }
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl<Element, A1, A2, I1, I2> PartialEq<JaggedArray<Element, A2, I2>>
+    for JaggedArray<Element, A1, I1>
+where
+    Element: PartialEq,
+    A1: Array<Item = usize>,
+    A2: Array<Item = usize>,
+    I1: Idx,
+    I2: Idx,
+{
+    fn eq(&self, other: &JaggedArray<Element, A2, I2>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<Element: fmt::Debug, A: Array<Item = usize>, I: Idx> fmt::Debug
+    for JaggedArray<Element, A, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<Element: Eq, A: Array<Item = usize>, I: Idx> Eq for JaggedArray<Element, A, I> {}
+
+impl<Element: Hash, A: Array<Item = usize>, I: Idx> Hash for JaggedArray<Element, A, I> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash the logical row structure so that structurally-equal arrays hash
+        // equally regardless of how `indices` is stored internally. Slices hash
+        // their length before their elements, so row lengths are captured too.
+        self.len().hash(state);
+        for row in self.iter() {
+            row.hash(state);
+        }
+    }
+}
+
+impl<Element: Ord, A: Array<Item = usize>, I: Idx> Ord for JaggedArray<Element, A, I> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<Element: Ord, A: Array<Item = usize>, I: Idx> PartialOrd for JaggedArray<Element, A, I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Element: PartialEq, A: Array<Item = usize>, I: Idx> PartialEq<[Vec<Element>]>
+    for JaggedArray<Element, A, I> {
+    fn eq(&self, other: &[Vec<Element>]) -> bool {
+        self.eq_rows(other.iter().map(|row| row.as_slice()))
+    }
+}
+
+impl<'b, Element: PartialEq, A: Array<Item = usize>, I: Idx> PartialEq<&'b [Vec<Element>]>
+    for JaggedArray<Element, A, I> {
+    fn eq(&self, other: &&'b [Vec<Element>]) -> bool {
+        self.eq_rows(other.iter().map(|row| row.as_slice()))
+    }
+}
+
+impl<Element: PartialEq, A: Array<Item = usize>, I: Idx> PartialEq<Vec<Vec<Element>>>
+    for JaggedArray<Element, A, I> {
+    fn eq(&self, other: &Vec<Vec<Element>>) -> bool {
+        self.eq_rows(other.iter().map(|row| row.as_slice()))
+    }
+}
+
+impl<'b, Element: PartialEq, A: Array<Item = usize>, I: Idx> PartialEq<&'b [&'b [Element]]>
+    for JaggedArray<Element, A, I> {
+    fn eq(&self, other: &&'b [&'b [Element]]) -> bool {
+        self.eq_rows(other.iter().copied())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, Element, A, I> arbitrary::Arbitrary<'a> for JaggedArray<Element, A, I>
+where
+    Element: arbitrary::Arbitrary<'a>,
+    A: Array<Item = usize>,
+    I: Idx,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Build the flat buffers in a single pass: decide a row count, then for
+        // each row pull a length and that many elements, recording the
+        // cumulative end offset. This keeps `indices` monotonic and bounded
+        // by construction, so no generated value can be internally inconsistent.
+        let mut out: Self = Default::default();
+        let row_count = u.arbitrary_len::<Element>()?;
+
+        for _ in 0..row_count {
+            let len = u.arbitrary_len::<Element>()?;
+            for _ in 0..len {
+                out.elements.push(Element::arbitrary(u)?);
+            }
+
+            out.indices.push(out.elements.len());
+        }
+
+        Ok(out)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,4 +662,160 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    fn rows<A: Array<Item = usize>>(jagged: &JaggedArray<i32, A>) -> Vec<Vec<i32>> {
+        jagged.iter().map(|slice| slice.to_owned()).collect()
+    }
+
+    #[test]
+    fn assert_pop_returns_last_row() {
+        let input = [vec![1, 2, 3, 4, 5], vec![2, 3, 4], vec![2; 5]];
+
+        let mut jagged: JaggedArray<_> = input.iter().collect();
+
+        assert_eq!(jagged.pop(), Some(vec![2; 5]));
+        assert_eq!(rows(&jagged), vec![vec![1, 2, 3, 4, 5], vec![2, 3, 4]]);
+    }
+
+    #[test]
+    fn assert_remove_drops_middle_row() {
+        let input = [vec![1, 2, 3, 4, 5], vec![2, 3, 4], vec![2; 5]];
+
+        let mut jagged: JaggedArray<_> = input.iter().collect();
+
+        assert_eq!(jagged.remove(1), vec![2, 3, 4]);
+        assert_eq!(rows(&jagged), vec![vec![1, 2, 3, 4, 5], vec![2; 5]]);
+    }
+
+    #[test]
+    fn assert_insert_splices_row() {
+        let input = [vec![1, 2, 3, 4, 5], vec![2; 5]];
+
+        let mut jagged: JaggedArray<_> = input.iter().collect();
+
+        jagged.insert(1, &[7, 8, 9]);
+        assert_eq!(
+            rows(&jagged),
+            vec![vec![1, 2, 3, 4, 5], vec![7, 8, 9], vec![2; 5]]
+        );
+    }
+
+    #[test]
+    fn assert_swap_exchanges_rows() {
+        let input = [vec![1, 2, 3, 4, 5], vec![2, 3, 4], vec![2; 5]];
+
+        let mut jagged: JaggedArray<_> = input.iter().collect();
+
+        jagged.swap(0, 2);
+        assert_eq!(rows(&jagged), vec![vec![2; 5], vec![2, 3, 4], vec![1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    fn assert_truncate_and_clear() {
+        let input = [vec![1, 2, 3, 4, 5], vec![2, 3, 4], vec![2; 5]];
+
+        let mut jagged: JaggedArray<_> = input.iter().collect();
+
+        jagged.truncate(1);
+        assert_eq!(rows(&jagged), vec![vec![1, 2, 3, 4, 5]]);
+
+        jagged.clear();
+        assert!(rows(&jagged).is_empty());
+    }
+
+    #[test]
+    fn assert_into_iter_yields_owned_rows() {
+        let input = vec![vec![1, 2, 3, 4, 5], vec![2, 3, 4], vec![2; 5]];
+
+        let jagged: JaggedArray<_> = input.iter().collect();
+
+        let output: Vec<Vec<_>> = jagged.into_iter().collect();
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn assert_into_iter_is_double_ended() {
+        let input = [vec![1, 2, 3, 4, 5], vec![2, 3, 4], vec![2; 5]];
+
+        let jagged: JaggedArray<_> = input.iter().collect();
+
+        let output: Vec<Vec<_>> = jagged.into_iter().rev().collect();
+
+        assert_eq!(output, vec![vec![2; 5], vec![2, 3, 4], vec![1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    fn assert_drain_empties_and_reuses() {
+        let input = vec![vec![1, 2, 3, 4, 5], vec![2, 3, 4], vec![2; 5]];
+
+        let mut jagged: JaggedArray<_> = input.iter().collect();
+
+        let drained: Vec<Vec<_>> = jagged.drain().collect();
+        assert_eq!(input, drained);
+        assert_eq!(jagged.len(), 0);
+
+        jagged.push(&[9, 9]);
+        assert_eq!(rows(&jagged), vec![vec![9, 9]]);
+    }
+
+    #[test]
+    fn assert_eq_against_nested_vec() {
+        let input = [vec![1, 2], vec![3]];
+
+        let jagged: JaggedArray<_> = input.iter().collect();
+
+        assert_eq!(jagged, vec![vec![1, 2], vec![3]]);
+        assert!(jagged != vec![vec![1, 2], vec![4]]);
+    }
+
+    #[test]
+    fn assert_eq_and_ord_between_jagged() {
+        let a: JaggedArray<i32> = [vec![1, 2], vec![3]].iter().collect();
+        let b: JaggedArray<i32> = [vec![1, 2], vec![3]].iter().collect();
+        let c: JaggedArray<i32> = [vec![1, 2], vec![4]].iter().collect();
+
+        assert_eq!(a, b);
+        assert!(a < c);
+    }
+
+    #[test]
+    fn assert_structural_hash_equality() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of(jagged: &JaggedArray<i32>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            jagged.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a: JaggedArray<i32> = [vec![1, 2], vec![3]].iter().collect();
+        let b: JaggedArray<i32> = [vec![1, 2], vec![3]].iter().collect();
+
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn assert_get_unchecked_returns_correct_slice() {
+        let input = vec![vec![1, 2, 3, 4, 5], vec![2, 3, 4], vec![2; 5]];
+
+        let jagged: JaggedArray<_> = input.iter().collect();
+
+        let mut output: Vec<Vec<_>> = Default::default();
+
+        for i in 0..jagged.len() {
+            output.push(unsafe { jagged.get_unchecked(i) }.to_owned());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn assert_with_capacity_reserves_both_buffers() {
+        let jagged: JaggedArray<i32> = JaggedArray::with_capacity(4, 32);
+
+        let (rows, elements) = jagged.capacity();
+        assert!(rows >= 4);
+        assert!(elements >= 32);
+    }
 }